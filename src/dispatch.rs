@@ -0,0 +1,267 @@
+use std::sync::OnceLock;
+
+/// A CPU-specific SIMD backend for the dot-product kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Backend {
+    #[cfg(target_arch = "x86_64")]
+    Avx512,
+    #[cfg(target_arch = "x86_64")]
+    Avx2,
+    #[cfg(target_arch = "x86_64")]
+    Sse2,
+    #[cfg(target_arch = "aarch64")]
+    Neon,
+    /// No specialized kernel for this CPU; `f64` falls back to
+    /// [`dot_portable`], and every other element type keeps using
+    /// `std::simd`'s portable vectors at a conservative, universally-safe
+    /// lane width (see [`Self::accumulator_lanes`]).
+    Portable,
+}
+
+impl Backend {
+    #[cfg(target_arch = "x86_64")]
+    fn detect() -> Self {
+        if is_x86_feature_detected!("avx512f") {
+            Backend::Avx512
+        } else if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            Backend::Avx2
+        } else if is_x86_feature_detected!("sse2") {
+            Backend::Sse2
+        } else {
+            Backend::Portable
+        }
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    fn detect() -> Self {
+        if std::arch::is_aarch64_feature_detected!("neon") {
+            Backend::Neon
+        } else {
+            Backend::Portable
+        }
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    fn detect() -> Self {
+        Backend::Portable
+    }
+
+    /// Lane width the portable `std::simd` dot-product accumulator should
+    /// run at for this backend. Consulted by [`crate::matrix::portable_dot`]:
+    /// for element types (like `f32`) that don't have a hand-written kernel
+    /// in [`f64_dot_kernel`], and for `f64` when that falls back to
+    /// [`dot_portable`].
+    pub(crate) fn accumulator_lanes(self) -> usize {
+        match self {
+            #[cfg(target_arch = "x86_64")]
+            // 512-bit registers hold 8 lanes of f64 (or 16 of f32, which just
+            // means f32 dot products burn through their accumulator faster).
+            Backend::Avx512 => 8,
+            #[cfg(target_arch = "x86_64")]
+            // 256-bit registers: 4 lanes of f64.
+            Backend::Avx2 => 4,
+            #[cfg(target_arch = "x86_64")]
+            // 128-bit registers: 2 lanes of f64.
+            Backend::Sse2 => 2,
+            #[cfg(target_arch = "aarch64")]
+            Backend::Neon => 2,
+            Backend::Portable => 4,
+        }
+    }
+}
+
+/// Detects the best backend for the current CPU on first call and caches it
+/// for every call after.
+pub(crate) fn backend() -> Backend {
+    static CACHE: OnceLock<Backend> = OnceLock::new();
+    *CACHE.get_or_init(Backend::detect)
+}
+
+/// A dot-product kernel specialized for one CPU backend. `unsafe` because
+/// every kernel but [`dot_portable`] requires its target feature to actually
+/// be present on the running CPU — callers must only invoke the kernel
+/// [`f64_dot_kernel`] returned for this process.
+pub(crate) type F64DotKernel = unsafe fn(&[f64], &[f64]) -> f64;
+
+/// Picks and caches the real per-ISA kernel for `f64` dot products: each
+/// [`Backend`] maps to genuinely different machine code (raw `core::arch`
+/// intrinsics, not just a different `std::simd` lane width), selected once
+/// behind a function pointer rather than re-checked per call.
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn f64_dot_kernel() -> F64DotKernel {
+    static CACHE: OnceLock<F64DotKernel> = OnceLock::new();
+    *CACHE.get_or_init(|| match backend() {
+        Backend::Avx512 => dot_avx512 as F64DotKernel,
+        Backend::Avx2 => dot_avx2_fma as F64DotKernel,
+        Backend::Sse2 => dot_sse2 as F64DotKernel,
+        Backend::Portable => dot_portable as F64DotKernel,
+    })
+}
+
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn f64_dot_kernel() -> F64DotKernel {
+    static CACHE: OnceLock<F64DotKernel> = OnceLock::new();
+    *CACHE.get_or_init(|| match backend() {
+        Backend::Neon => dot_neon as F64DotKernel,
+        Backend::Portable => dot_portable as F64DotKernel,
+    })
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+pub(crate) fn f64_dot_kernel() -> F64DotKernel {
+    static CACHE: OnceLock<F64DotKernel> = OnceLock::new();
+    *CACHE.get_or_init(|| dot_portable as F64DotKernel)
+}
+
+/// AVX-512F kernel: 8 lanes of `f64` per register, FMA'd into a single
+/// accumulator, reduced once at the end.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx512f")]
+unsafe fn dot_avx512(vector1: &[f64], vector2: &[f64]) -> f64 {
+    use std::arch::x86_64::*;
+
+    let mut acc = _mm512_setzero_pd();
+    let mut i = 0;
+    while i+8 <= vector1.len() {
+        let a = _mm512_loadu_pd(vector1.as_ptr().add(i));
+        let b = _mm512_loadu_pd(vector2.as_ptr().add(i));
+        acc = _mm512_fmadd_pd(a, b, acc);
+        i += 8;
+    }
+
+    let mut sum = _mm512_reduce_add_pd(acc);
+    while i < vector1.len() {
+        sum += vector1[i]*vector2[i];
+        i += 1;
+    }
+    sum
+}
+
+/// AVX2+FMA kernel: 4 lanes of `f64` per register.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "avx2,fma")]
+unsafe fn dot_avx2_fma(vector1: &[f64], vector2: &[f64]) -> f64 {
+    use std::arch::x86_64::*;
+
+    let mut acc = _mm256_setzero_pd();
+    let mut i = 0;
+    while i+4 <= vector1.len() {
+        let a = _mm256_loadu_pd(vector1.as_ptr().add(i));
+        let b = _mm256_loadu_pd(vector2.as_ptr().add(i));
+        acc = _mm256_fmadd_pd(a, b, acc);
+        i += 4;
+    }
+
+    let lo = _mm256_castpd256_pd128(acc);
+    let hi = _mm256_extractf128_pd(acc, 1);
+    let sum128 = _mm_add_pd(lo, hi);
+    let shuf = _mm_unpackhi_pd(sum128, sum128);
+    let mut sum = _mm_cvtsd_f64(_mm_add_sd(sum128, shuf));
+
+    while i < vector1.len() {
+        sum += vector1[i]*vector2[i];
+        i += 1;
+    }
+    sum
+}
+
+/// SSE2 kernel: 2 lanes of `f64` per register, the narrowest x86_64
+/// guarantees.
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "sse2")]
+unsafe fn dot_sse2(vector1: &[f64], vector2: &[f64]) -> f64 {
+    use std::arch::x86_64::*;
+
+    let mut acc = _mm_setzero_pd();
+    let mut i = 0;
+    while i+2 <= vector1.len() {
+        let a = _mm_loadu_pd(vector1.as_ptr().add(i));
+        let b = _mm_loadu_pd(vector2.as_ptr().add(i));
+        acc = _mm_add_pd(acc, _mm_mul_pd(a, b));
+        i += 2;
+    }
+
+    let shuf = _mm_unpackhi_pd(acc, acc);
+    let mut sum = _mm_cvtsd_f64(_mm_add_sd(acc, shuf));
+
+    while i < vector1.len() {
+        sum += vector1[i]*vector2[i];
+        i += 1;
+    }
+    sum
+}
+
+/// NEON kernel: 2 lanes of `f64` per register.
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "neon")]
+unsafe fn dot_neon(vector1: &[f64], vector2: &[f64]) -> f64 {
+    use std::arch::aarch64::*;
+
+    let mut acc = vdupq_n_f64(0.0);
+    let mut i = 0;
+    while i+2 <= vector1.len() {
+        let a = vld1q_f64(vector1.as_ptr().add(i));
+        let b = vld1q_f64(vector2.as_ptr().add(i));
+        acc = vfmaq_f64(acc, a, b);
+        i += 2;
+    }
+
+    let mut sum = vaddvq_f64(acc);
+    while i < vector1.len() {
+        sum += vector1[i]*vector2[i];
+        i += 1;
+    }
+    sum
+}
+
+/// Fallback used on CPUs with no hand-written kernel: the same
+/// `std::simd`-vectorized [`crate::matrix::portable_dot`] path `f32` (and any
+/// other element type without a per-ISA kernel) uses, run at the lane width
+/// [`Backend::accumulator_lanes`] picks — not a scalar triple loop. Marked
+/// `unsafe` only so it fits [`F64DotKernel`]'s signature alongside the
+/// intrinsic-based kernels; the body itself does nothing unsafe.
+unsafe fn dot_portable(vector1: &[f64], vector2: &[f64]) -> f64 {
+    crate::matrix::portable_dot(vector1, vector2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backend_is_cached_across_calls() {
+        assert_eq!(backend(), backend());
+    }
+
+    #[test]
+    fn f64_dot_kernel_is_cached_across_calls() {
+        assert_eq!(f64_dot_kernel() as usize, f64_dot_kernel() as usize);
+    }
+
+    #[test]
+    fn f64_dot_kernel_matches_scalar_reference() {
+        let a: Vec<f64> = (0..37).map(|x| x as f64).collect();
+        let b: Vec<f64> = (0..37).map(|x| (x as f64)*0.5 + 1.0).collect();
+        let expected: f64 = a.iter().zip(&b).map(|(x, y)| x*y).sum();
+
+        let kernel = f64_dot_kernel();
+        // SAFETY: `f64_dot_kernel` only ever returns a kernel whose target
+        // feature was runtime-detected as present on this CPU.
+        let actual = unsafe { kernel(&a, &b) };
+
+        assert!((actual-expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn every_backend_has_a_supported_lane_width() {
+        assert!([2, 4, 8].contains(&Backend::Portable.accumulator_lanes()));
+
+        #[cfg(target_arch = "x86_64")]
+        for b in [Backend::Avx512, Backend::Avx2, Backend::Sse2] {
+            assert!([2, 4, 8].contains(&b.accumulator_lanes()));
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        assert!([2, 4, 8].contains(&Backend::Neon.accumulator_lanes()));
+    }
+}
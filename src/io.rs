@@ -0,0 +1,211 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::matrix::FloatMatrix;
+
+/// Errors that can occur while parsing a [`FloatMatrix`] from text.
+#[derive(Debug)]
+pub enum MatrixIoError {
+    /// A Matrix Market file didn't start with `%%MatrixMarket`, or its
+    /// dimension line had fewer than three fields.
+    InvalidHeader,
+    /// A row of a whitespace-grid matrix had a different number of columns
+    /// than the rows before it.
+    RowLengthMismatch { row: usize, expected: usize, found: usize },
+    /// A Matrix Market coordinate entry named a row or column outside the
+    /// declared `rows x cols` bounds.
+    OutOfBounds { row: usize, col: usize, rows: usize, cols: usize },
+    /// The Matrix Market header declared a different `nnz` than the number
+    /// of coordinate entries actually present.
+    NnzMismatch { expected: usize, found: usize },
+    /// A Matrix Market input ended before its header or dimension line, or a
+    /// coordinate entry had fewer than three fields.
+    UnexpectedEof,
+    ParseFloat(std::num::ParseFloatError),
+    ParseInt(std::num::ParseIntError),
+}
+
+impl fmt::Display for MatrixIoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixIoError::InvalidHeader => write!(f, "missing or invalid Matrix Market header"),
+            MatrixIoError::RowLengthMismatch { row, expected, found } =>
+                write!(f, "row {row} has {found} columns, expected {expected}"),
+            MatrixIoError::OutOfBounds { row, col, rows, cols } =>
+                write!(f, "coordinate ({row}, {col}) is out of bounds for a {rows}x{cols} matrix"),
+            MatrixIoError::NnzMismatch { expected, found } =>
+                write!(f, "header declared {expected} nonzero entries, found {found}"),
+            MatrixIoError::UnexpectedEof => write!(f, "unexpected end of input"),
+            MatrixIoError::ParseFloat(e) => write!(f, "invalid number: {e}"),
+            MatrixIoError::ParseInt(e) => write!(f, "invalid integer: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for MatrixIoError {}
+
+impl FromStr for FloatMatrix {
+    type Err = MatrixIoError;
+
+    /// Parses a whitespace/newline grid: rows separated by newlines, columns
+    /// within a row separated by spaces. Every row must have the same
+    /// number of columns.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut data = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let row_start = data.len();
+            for token in line.split_whitespace() {
+                data.push(token.parse().map_err(MatrixIoError::ParseFloat)?);
+            }
+            let row_len = data.len()-row_start;
+
+            match width {
+                None => width = Some(row_len),
+                Some(expected) if expected != row_len =>
+                    return Err(MatrixIoError::RowLengthMismatch { row: height, expected, found: row_len }),
+                _ => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(FloatMatrix::new(data, width.unwrap_or(0), height))
+    }
+}
+
+impl fmt::Display for FloatMatrix {
+    /// Writes the same whitespace/newline grid format [`FloatMatrix::from_str`] reads.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (_, height) = self.dim();
+
+        for row in 0..height {
+            let row_slice = self.get_row(row);
+            for (col, val) in row_slice.iter().enumerate() {
+                if col > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{val}")?;
+            }
+            if row+1 < height {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FloatMatrix {
+    /// Reads the coordinate (sparse triplet) Matrix Market format: a
+    /// `%%MatrixMarket` header line, optional `%`-prefixed comments, a
+    /// `rows cols nnz` dimension line, then `nnz` `row col value` triples
+    /// (1-indexed, per the format's convention). Materializes into dense
+    /// storage, zero-filling every coordinate not listed.
+    pub fn from_matrix_market(input: &str) -> Result<Self, MatrixIoError> {
+        let mut lines = input.lines().map(str::trim).filter(|l| !l.is_empty());
+
+        let header = lines.next().ok_or(MatrixIoError::UnexpectedEof)?;
+        if !header.starts_with("%%MatrixMarket") {
+            return Err(MatrixIoError::InvalidHeader);
+        }
+
+        let dims_line = lines.find(|l| !l.starts_with('%')).ok_or(MatrixIoError::UnexpectedEof)?;
+        let mut dims = dims_line.split_whitespace();
+        let rows: usize = dims.next().ok_or(MatrixIoError::InvalidHeader)?.parse().map_err(MatrixIoError::ParseInt)?;
+        let cols: usize = dims.next().ok_or(MatrixIoError::InvalidHeader)?.parse().map_err(MatrixIoError::ParseInt)?;
+        let nnz: usize = dims.next().ok_or(MatrixIoError::InvalidHeader)?.parse().map_err(MatrixIoError::ParseInt)?;
+
+        let mut data = vec![0.0; rows*cols];
+        let mut seen = 0;
+
+        for line in lines {
+            let mut parts = line.split_whitespace();
+            let row: usize = parts.next().ok_or(MatrixIoError::UnexpectedEof)?.parse().map_err(MatrixIoError::ParseInt)?;
+            let col: usize = parts.next().ok_or(MatrixIoError::UnexpectedEof)?.parse().map_err(MatrixIoError::ParseInt)?;
+            let val: f64 = parts.next().ok_or(MatrixIoError::UnexpectedEof)?.parse().map_err(MatrixIoError::ParseFloat)?;
+
+            if row == 0 || col == 0 || row > rows || col > cols {
+                return Err(MatrixIoError::OutOfBounds { row, col, rows, cols });
+            }
+
+            data[(row-1)*cols + (col-1)] = val;
+            seen += 1;
+        }
+
+        if seen != nnz {
+            return Err(MatrixIoError::NnzMismatch { expected: nnz, found: seen });
+        }
+
+        Ok(FloatMatrix::new(data, cols, rows))
+    }
+
+    /// Writes the coordinate Matrix Market format [`Self::from_matrix_market`] reads,
+    /// listing only the nonzero entries.
+    pub fn to_matrix_market(&self) -> String {
+        let (width, height) = self.dim();
+
+        let nonzeros: Vec<(usize, usize, f64)> = (0..height)
+            .flat_map(|row| {
+                let row_slice = self.get_row(row);
+                row_slice.iter().enumerate().filter_map(move |(col, &val)| (val != 0.0).then_some((row, col, val)))
+            })
+            .collect();
+
+        let mut out = String::new();
+        out.push_str("%%MatrixMarket matrix coordinate real general\n");
+        out.push_str(&format!("{height} {width} {}\n", nonzeros.len()));
+        for (row, col, val) in nonzeros {
+            out.push_str(&format!("{} {} {val}\n", row+1, col+1));
+        }
+
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_parses_grid() {
+        let m: FloatMatrix = "1 2 3\n4 5 6\n".parse().unwrap();
+        assert_eq!(m.as_raw(), vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn from_str_rejects_ragged_rows() {
+        let result: Result<FloatMatrix, _> = "1 2 3\n4 5\n".parse();
+        assert!(matches!(result, Err(MatrixIoError::RowLengthMismatch { row: 1, expected: 3, found: 2 })));
+    }
+
+    #[test]
+    fn display_round_trips_through_from_str() {
+        let m = FloatMatrix::new(vec![1.0, 2.0, 3.0, 4.0], 2, 2);
+        let rendered = m.to_string();
+        let parsed: FloatMatrix = rendered.parse().unwrap();
+        assert_eq!(parsed, m);
+    }
+
+    #[test]
+    fn matrix_market_round_trip() {
+        let m = FloatMatrix::new(vec![1.0, 0.0, 0.0, 2.0], 2, 2);
+        let written = m.to_matrix_market();
+        let parsed = FloatMatrix::from_matrix_market(&written).unwrap();
+        assert_eq!(parsed, m);
+    }
+
+    #[test]
+    fn matrix_market_rejects_nnz_mismatch() {
+        let input = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 5.0\n";
+        let result = FloatMatrix::from_matrix_market(input);
+        assert!(matches!(result, Err(MatrixIoError::NnzMismatch { expected: 2, found: 1 })));
+    }
+}
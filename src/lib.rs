@@ -0,0 +1,9 @@
+#![feature(portable_simd)]
+#![cfg_attr(feature = "fast-float", feature(core_intrinsics))]
+#![cfg_attr(feature = "fast-float", allow(internal_features))]
+
+mod dispatch;
+pub mod matrix;
+pub mod sparse;
+#[cfg(feature = "io")]
+pub mod io;
@@ -15,7 +15,7 @@ fn main() {
         test_matrices_2.push(FloatMatrix::new(vec![15.0; i.pow(2)], i, i));
     }
     
-    for (i, (m1, m2)) in test_matrices_1.into_iter().zip(test_matrices_2.into_iter()).enumerate() {
+    for (i, (m1, m2)) in test_matrices_1.into_iter().zip(test_matrices_2).enumerate() {
         let m1_1 = m1.clone();
         let m2_1 = m2.clone();
         let simd_start = Instant::now();
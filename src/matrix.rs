@@ -1,29 +1,99 @@
 use std::ops::Mul;
 
 use std::simd;
-use std::simd::SimdFloat;
+use std::simd::Simd;
+use std::simd::num::SimdFloat;
+use std::simd::StdFloat;
+
+use crate::dispatch;
+
+/// Element type usable as the backing scalar of a [`Matrix`].
+///
+/// Abstracts over the handful of operations the SIMD dot-product and
+/// dispatch code need, so the same `Matrix<T>` machinery works for both
+/// `f32` and `f64` without duplicating it per type.
+pub trait SimdScalar: simd::SimdElement + Copy + Default + Mul<Output = Self> + std::ops::Add<Output = Self> {
+    /// Dot product of two equal-length slices. `f64` dispatches to the real
+    /// per-ISA kernel [`dispatch::f64_dot_kernel`] picks for the running CPU;
+    /// every other type falls back to [`portable_dot`]'s `std::simd` path.
+    fn dot(vector1: &[Self], vector2: &[Self]) -> Self;
+
+    /// Add two values while combining SIMD lane sums. Behind the
+    /// `fast-float` feature this is `core::intrinsics::fadd_fast`, which
+    /// lets the optimizer reassociate/reorder the adds at the cost of
+    /// undefined behavior if a NaN/inf ever reaches it; the default (used
+    /// with the feature off) is a plain, fully-defined `+`.
+    #[inline(always)]
+    fn add_fast(self, other: Self) -> Self {
+        self + other
+    }
+}
+
+impl SimdScalar for f64 {
+    fn dot(vector1: &[f64], vector2: &[f64]) -> f64 {
+        assert_eq!(vector1.len(), vector2.len());
+        let kernel = dispatch::f64_dot_kernel();
+        // SAFETY: `f64_dot_kernel` only ever returns a kernel whose required
+        // target feature was runtime-detected as present on this CPU.
+        unsafe { kernel(vector1, vector2) }
+    }
+
+    #[cfg(feature = "fast-float")]
+    #[inline(always)]
+    fn add_fast(self, other: f64) -> f64 {
+        // SAFETY: the matrix element types this crate supports (f32/f64) never carry
+        // NaN/inf through a dot product in the workloads this crate targets, so the
+        // relaxed-reassociation contract of fadd_fast holds.
+        unsafe { core::intrinsics::fadd_fast(self, other) }
+    }
+}
+
+impl SimdScalar for f32 {
+    fn dot(vector1: &[f32], vector2: &[f32]) -> f32 {
+        portable_dot(vector1, vector2)
+    }
+
+    #[cfg(feature = "fast-float")]
+    #[inline(always)]
+    fn add_fast(self, other: f32) -> f32 {
+        // SAFETY: see `<f64 as SimdScalar>::add_fast`.
+        unsafe { core::intrinsics::fadd_fast(self, other) }
+    }
+}
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct FloatMatrix {
-    data: Vec<f64>,
+pub struct Matrix<T: SimdScalar> {
+    data: Vec<T>,
     dim: (usize, usize)
 }
 
-impl FloatMatrix {
-    pub fn new(data: Vec<f64>, width: usize, height: usize) -> Self {
+/// Double-precision matrix. This was the crate's original (and only) element type.
+pub type FloatMatrix = Matrix<f64>;
+/// Single-precision matrix. Its dot products run through `portable_dot` at
+/// the same lane count `f64` gets there; the lane count is not doubled for
+/// the narrower element type.
+pub type F32Matrix = Matrix<f32>;
+
+impl<T: SimdScalar> Matrix<T> {
+    pub fn new(data: Vec<T>, width: usize, height: usize) -> Self {
         assert_eq!(data.len(), width*height);
         Self { data, dim: (width, height) }
     }
 
-    pub fn as_raw(self) -> Vec<f64> {
+    pub fn as_raw(self) -> Vec<T> {
         self.data
     }
 
+    /// `(width, height)` of the matrix.
+    pub fn dim(&self) -> (usize, usize) {
+        self.dim
+    }
+
     pub fn get_transpose(&self) -> Self {
         let mut transposed = Vec::with_capacity(self.data.len());
         (0..self.data.len())
             .map(|n| (n / self.dim.1) + ((n%self.dim.1)*self.dim.0))
-            .for_each(|n| transposed.push(self.data.get(n).unwrap().clone()));
+            .for_each(|n| transposed.push(*self.data.get(n).unwrap()));
 
         Self { data: transposed, dim: (self.dim.1, self.dim.0) }
     }
@@ -34,10 +104,12 @@ impl FloatMatrix {
         for row_i in 0..self.dim.1 {
             let row = self.get_row(row_i);
             for col_i in 0..rhs.dim.0 {
+                let mut sum = T::default();
                 for (i, val1) in row.iter().enumerate() {
                     let val2 = rhs.get_row(i)[col_i];
-                    new_data.push(val1*val2)
+                    sum = sum + *val1*val2;
                 }
+                new_data.push(sum);
             }
         }
 
@@ -45,7 +117,7 @@ impl FloatMatrix {
     }
 
     #[inline]
-    fn get_row(&self, n: usize) -> &[f64] {
+    pub(crate) fn get_row(&self, n: usize) -> &[T] {
         &self.data[n*self.dim.0..(n+1)*self.dim.0]
     }
 }
@@ -55,33 +127,75 @@ macro_rules! lane_size_mult {
     ($rem: ident, $sum: ident, $vector1: ident, $vector2: ident, $lane_size: literal) => {
         if $rem >= $lane_size {
             let current_len = $vector1.len()-$rem;
-            let next_simd_vector_one: simd::Simd<f64, $lane_size> = simd::Simd::from_slice(&$vector1[current_len..current_len+$lane_size]);
-            let next_simd_vector_two: simd::Simd<f64, $lane_size> = simd::Simd::from_slice(&$vector2[current_len..current_len+$lane_size]);
+            let next_simd_vector_one: Simd<T, $lane_size> = Simd::from_slice(&$vector1[current_len..current_len+$lane_size]);
+            let next_simd_vector_two: Simd<T, $lane_size> = Simd::from_slice(&$vector2[current_len..current_len+$lane_size]);
             let result = next_simd_vector_one * next_simd_vector_two;
-            $sum += result.reduce_sum();
+            $sum = $sum.add_fast(result.reduce_sum());
             $rem -= $lane_size;
         }
     };
 }
 
+/// Dot product of two equal-length slices, dispatching to [`SimdScalar::dot`].
+pub(crate) fn execute_mult_sum_simd<T: SimdScalar>(vector1: &[T], vector2: &[T]) -> T {
+    T::dot(vector1, vector2)
+}
+
+/// Portable fallback used by [`SimdScalar`] impls with no hand-written
+/// per-ISA kernel: dispatches only to the lane width [`dispatch::backend`]
+/// picked for the CPU running this process (detected once and cached, not
+/// re-checked per call), not to different machine code per backend.
+pub(crate) fn portable_dot<T>(vector1: &[T], vector2: &[T]) -> T
+where
+    T: SimdScalar,
+    Simd<T, 8>: SimdFloat<Scalar = T> + StdFloat + Mul<Output = Simd<T, 8>>,
+    Simd<T, 4>: SimdFloat<Scalar = T> + StdFloat + Mul<Output = Simd<T, 4>>,
+    Simd<T, 2>: SimdFloat<Scalar = T> + StdFloat + Mul<Output = Simd<T, 2>>,
+{
+    match dispatch::backend().accumulator_lanes() {
+        8 => dot_with_accumulator_lanes::<T, 8>(vector1, vector2),
+        4 => dot_with_accumulator_lanes::<T, 4>(vector1, vector2),
+        _ => dot_with_accumulator_lanes::<T, 2>(vector1, vector2),
+    }
+}
 
-fn execute_mult_sum_simd(vector1: &[f64], vector2: &[f64]) -> f64 {
+/// The persistent-FMA dot product itself, parameterized over the
+/// accumulator's lane width so each `dispatch::Backend` can run its natural
+/// size. Sub-lane remainders still fall through the 4-then-2-then-scalar
+/// cascade regardless of `LANES`.
+fn dot_with_accumulator_lanes<T, const LANES: usize>(vector1: &[T], vector2: &[T]) -> T
+where
+    T: SimdScalar,
+    Simd<T, LANES>: SimdFloat<Scalar = T> + StdFloat,
+    Simd<T, 4>: SimdFloat<Scalar = T> + StdFloat + Mul<Output = Simd<T, 4>>,
+    Simd<T, 2>: SimdFloat<Scalar = T> + StdFloat + Mul<Output = Simd<T, 2>>,
+{
     assert_eq!(vector1.len(), vector2.len());
 
+    let full_chunks = vector1.len() / LANES;
+    let mut acc = Simd::<T, LANES>::splat(T::default());
+
+    for chunk in 0..full_chunks {
+        let offset = chunk*LANES;
+        let a: Simd<T, LANES> = Simd::from_slice(&vector1[offset..offset+LANES]);
+        let b: Simd<T, LANES> = Simd::from_slice(&vector2[offset..offset+LANES]);
+        acc = a.mul_add(b, acc);
+    }
+
+    let mut sum = acc.reduce_sum();
+
+    let tail_offset = full_chunks*LANES;
+    let vector1 = &vector1[tail_offset..];
+    let vector2 = &vector2[tail_offset..];
     let mut remaining_length = vector1.len();
-    let mut sum = 0.0;
 
     while remaining_length > 0 {
-        lane_size_mult!(remaining_length, sum, vector1, vector2, 64);
-        lane_size_mult!(remaining_length, sum, vector1, vector2, 32);
-        lane_size_mult!(remaining_length, sum, vector1, vector2, 16);
-        lane_size_mult!(remaining_length, sum, vector1, vector2, 8);
         lane_size_mult!(remaining_length, sum, vector1, vector2, 4);
         lane_size_mult!(remaining_length, sum, vector1, vector2, 2);
 
         // simd for one value is slower than normal multiplication
         if remaining_length == 1 {
-            sum += vector1.last().unwrap()*vector2.last().unwrap();
+            sum = sum.add_fast(*vector1.last().unwrap()**vector2.last().unwrap());
             remaining_length = 0;
         }
     }
@@ -89,40 +203,334 @@ fn execute_mult_sum_simd(vector1: &[f64], vector2: &[f64]) -> f64 {
     sum
 }
 
-impl Mul for FloatMatrix {
-    type Output = FloatMatrix;
+/// Below this size (the larger of the two output dimensions) the packing
+/// overhead of [`Matrix::blocked_mult`] isn't worth it; [`Matrix::naive_mult`]
+/// wins outright on tiny matrices.
+const BLOCKED_MULT_THRESHOLD: usize = 64;
+
+/// Cache-block sizes for [`Matrix::blocked_mult`], in elements. `MC`/`KC` are
+/// chosen so an `MC`-by-`KC` panel of `A` sits comfortably in L2, and `KC`-by-`NC`
+/// panel of `B` in L1; `MR`/`NR` size the inner tile of output entries the
+/// microkernel walks per step. Each entry's dot product is added straight
+/// into the output; there is no separate register tile.
+const MC: usize = 256;
+const KC: usize = 256;
+const NC: usize = 256;
+const MR: usize = 4;
+const NR: usize = 4;
+
+impl<T: SimdScalar> Matrix<T> {
+    /// Cache-blocked (tiled) GEMM. Instead of materializing a full transpose of
+    /// `rhs` up front, packs small `A`/`B` panels sized to fit L1/L2 into
+    /// contiguous scratch buffers and runs the SIMD FMA microkernel over each
+    /// packed panel, accumulating into the output tile in place.
+    pub fn blocked_mult(self, rhs: Self) -> Self {
+        assert_eq!(self.dim.0, rhs.dim.1);
+
+        let m = self.dim.1;
+        let k = self.dim.0;
+        let n = rhs.dim.0;
+
+        let mut out = vec![T::default(); n*m];
+        let mut a_panel: Vec<T> = Vec::with_capacity(MC*KC);
+        let mut b_panel: Vec<T> = Vec::with_capacity(KC*NC);
+
+        let mut jc = 0;
+        while jc < n {
+            let nc = NC.min(n-jc);
+
+            let mut pc = 0;
+            while pc < k {
+                let kc = KC.min(k-pc);
+
+                // Pack the B panel column-major: kc-long contiguous runs, one per column.
+                b_panel.clear();
+                for col in 0..nc {
+                    for row in 0..kc {
+                        b_panel.push(rhs.get_row(pc+row)[jc+col]);
+                    }
+                }
+
+                let mut ic = 0;
+                while ic < m {
+                    let mc = MC.min(m-ic);
+
+                    // Pack the A panel row-major: kc-long contiguous runs, one per row.
+                    a_panel.clear();
+                    for row in 0..mc {
+                        let a_row = self.get_row(ic+row);
+                        a_panel.extend_from_slice(&a_row[pc..pc+kc]);
+                    }
+
+                    let mut i = 0;
+                    while i < mc {
+                        let mr = MR.min(mc-i);
+                        let mut j = 0;
+                        while j < nc {
+                            let nr = NR.min(nc-j);
+
+                            for ti in 0..mr {
+                                let a_slice = &a_panel[(i+ti)*kc..(i+ti)*kc+kc];
+                                for tj in 0..nr {
+                                    let b_slice = &b_panel[(j+tj)*kc..(j+tj)*kc+kc];
+                                    let dot = execute_mult_sum_simd(a_slice, b_slice);
+
+                                    let out_index = (ic+i+ti)*n + (jc+j+tj);
+                                    out[out_index] = out[out_index].add_fast(dot);
+                                }
+                            }
+
+                            j += nr;
+                        }
+                        i += mr;
+                    }
+
+                    ic += mc;
+                }
+
+                pc += kc;
+            }
+
+            jc += nc;
+        }
+
+        Self { data: out, dim: (n, m) }
+    }
+}
+
+impl<T: SimdScalar> Mul for Matrix<T> {
+    type Output = Matrix<T>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         if self.dim.0 == 0 || self.dim.1 == 0 || rhs.dim.0 == 0 || rhs.dim.1 == 0 {
-            return FloatMatrix::new(vec![], 0, 0);
+            return Matrix::new(vec![], 0, 0);
         }
 
-        if !is_x86_feature_detected!("avx2") {
+        assert_eq!(self.dim.0, rhs.dim.1);
+
+        if self.dim.1.max(rhs.dim.0) < BLOCKED_MULT_THRESHOLD {
             return self.naive_mult(rhs)
         }
 
-        assert_eq!(self.dim.0, rhs.dim.1);
+        self.blocked_mult(rhs)
+    }
+}
+
+/// Below this magnitude a 4x4 determinant, or a pivot in the Gauss-Jordan
+/// path, is treated as singular and [`FloatMatrix::inverse`] returns `None`
+/// rather than dividing by it.
+const INVERSE_EPSILON: f64 = 1e-12;
+
+impl FloatMatrix {
+    /// Determinant of a square matrix. Uses the same closed-form 2x2-minor
+    /// expansion as [`Self::inverse`] for the common 4x4 case, and falls back
+    /// to Gaussian elimination with partial pivoting otherwise.
+    pub fn det(&self) -> f64 {
+        assert_eq!(self.dim.0, self.dim.1, "det is only defined for square matrices");
+        let n = self.dim.0;
+
+        if n == 4 {
+            return Self::det_4x4(self.get_row(0), self.get_row(1), self.get_row(2), self.get_row(3));
+        }
+
+        self.gauss_jordan_det()
+    }
+
+    /// Branch-free SIMD inverse for 4x4 matrices, with a general
+    /// Gauss-Jordan fallback for every other square size. Returns `None`
+    /// when the matrix is singular: for 4x4, when the determinant's magnitude
+    /// is below [`INVERSE_EPSILON`]; otherwise, when a pivot's is.
+    pub fn inverse(&self) -> Option<FloatMatrix> {
+        assert_eq!(self.dim.0, self.dim.1, "inverse is only defined for square matrices");
+
+        if self.dim.0 == 4 {
+            return self.inverse_4x4();
+        }
+
+        self.inverse_gauss_jordan()
+    }
+
+    /// The 12 pairwise products needed for the 2x2 sub-determinants of the
+    /// bottom-half rows (`row2`, `row3`) against the top-half rows, and vice
+    /// versa, used by both [`Self::inverse_4x4`] and [`Self::det_4x4`].
+    fn cofactor_minors(row2: &[f64], row3: &[f64]) -> [f64; 12] {
+        [
+            row2[2]*row3[3], row2[3]*row3[2],
+            row2[1]*row3[3], row2[3]*row3[1],
+            row2[1]*row3[2], row2[2]*row3[1],
+            row2[0]*row3[3], row2[3]*row3[0],
+            row2[0]*row3[2], row2[2]*row3[0],
+            row2[0]*row3[1], row2[1]*row3[0],
+        ]
+    }
+
+    fn det_4x4(row0: &[f64], row1: &[f64], row2: &[f64], row3: &[f64]) -> f64 {
+        let tmp = Self::cofactor_minors(row2, row3);
+
+        let cofactor_row0 = Simd::from_array([
+            tmp[0]*row1[1] + tmp[3]*row1[2] + tmp[4]*row1[3] - tmp[1]*row1[1] - tmp[2]*row1[2] - tmp[5]*row1[3],
+            tmp[1]*row1[0] + tmp[6]*row1[2] + tmp[9]*row1[3] - tmp[0]*row1[0] - tmp[7]*row1[2] - tmp[8]*row1[3],
+            tmp[2]*row1[0] + tmp[7]*row1[1] + tmp[10]*row1[3] - tmp[3]*row1[0] - tmp[6]*row1[1] - tmp[11]*row1[3],
+            tmp[5]*row1[0] + tmp[8]*row1[1] + tmp[11]*row1[2] - tmp[4]*row1[0] - tmp[9]*row1[1] - tmp[10]*row1[2],
+        ]);
+
+        let row0_simd: Simd<f64, 4> = Simd::from_slice(row0);
+        (row0_simd * cofactor_row0).reduce_sum()
+    }
+
+    /// Branch-free SIMD inverse of a 4x4 matrix via Cramer's rule: the 12
+    /// "minors" computed here are the 2x2 sub-determinants of each pair of
+    /// rows, which assemble directly into the adjugate without ever forming
+    /// a 3x3 sub-matrix.
+    fn inverse_4x4(&self) -> Option<FloatMatrix> {
+        let row0 = self.get_row(0);
+        let row1 = self.get_row(1);
+        let row2 = self.get_row(2);
+        let row3 = self.get_row(3);
+
+        let row0_simd: Simd<f64, 4> = Simd::from_slice(row0);
+
+        let tmp_bottom = Self::cofactor_minors(row2, row3);
+
+        // Cofactors of row 0/row 1, i.e. `C[0][*]`/`C[1][*]` — the same
+        // quantity `det_4x4` calls `cofactor_row0`. The adjugate is the
+        // *transpose* of the cofactor matrix, so these become adjugate
+        // columns, not rows; they're assembled into the inverse's rows below.
+        let cofactor_row0 = [
+            tmp_bottom[0]*row1[1] + tmp_bottom[3]*row1[2] + tmp_bottom[4]*row1[3] - tmp_bottom[1]*row1[1] - tmp_bottom[2]*row1[2] - tmp_bottom[5]*row1[3],
+            tmp_bottom[1]*row1[0] + tmp_bottom[6]*row1[2] + tmp_bottom[9]*row1[3] - tmp_bottom[0]*row1[0] - tmp_bottom[7]*row1[2] - tmp_bottom[8]*row1[3],
+            tmp_bottom[2]*row1[0] + tmp_bottom[7]*row1[1] + tmp_bottom[10]*row1[3] - tmp_bottom[3]*row1[0] - tmp_bottom[6]*row1[1] - tmp_bottom[11]*row1[3],
+            tmp_bottom[5]*row1[0] + tmp_bottom[8]*row1[1] + tmp_bottom[11]*row1[2] - tmp_bottom[4]*row1[0] - tmp_bottom[9]*row1[1] - tmp_bottom[10]*row1[2],
+        ];
+        let cofactor_row1 = [
+            tmp_bottom[1]*row0[1] + tmp_bottom[2]*row0[2] + tmp_bottom[5]*row0[3] - tmp_bottom[0]*row0[1] - tmp_bottom[3]*row0[2] - tmp_bottom[4]*row0[3],
+            tmp_bottom[0]*row0[0] + tmp_bottom[7]*row0[2] + tmp_bottom[8]*row0[3] - tmp_bottom[1]*row0[0] - tmp_bottom[6]*row0[2] - tmp_bottom[9]*row0[3],
+            tmp_bottom[3]*row0[0] + tmp_bottom[6]*row0[1] + tmp_bottom[11]*row0[3] - tmp_bottom[2]*row0[0] - tmp_bottom[7]*row0[1] - tmp_bottom[10]*row0[3],
+            tmp_bottom[4]*row0[0] + tmp_bottom[9]*row0[1] + tmp_bottom[10]*row0[2] - tmp_bottom[5]*row0[0] - tmp_bottom[8]*row0[1] - tmp_bottom[11]*row0[2],
+        ];
+
+        let tmp_top = Self::cofactor_minors(row0, row1);
+
+        let cofactor_row2 = [
+            tmp_top[0]*row3[1] + tmp_top[3]*row3[2] + tmp_top[4]*row3[3] - tmp_top[1]*row3[1] - tmp_top[2]*row3[2] - tmp_top[5]*row3[3],
+            tmp_top[1]*row3[0] + tmp_top[6]*row3[2] + tmp_top[9]*row3[3] - tmp_top[0]*row3[0] - tmp_top[7]*row3[2] - tmp_top[8]*row3[3],
+            tmp_top[2]*row3[0] + tmp_top[7]*row3[1] + tmp_top[10]*row3[3] - tmp_top[3]*row3[0] - tmp_top[6]*row3[1] - tmp_top[11]*row3[3],
+            tmp_top[5]*row3[0] + tmp_top[8]*row3[1] + tmp_top[11]*row3[2] - tmp_top[4]*row3[0] - tmp_top[9]*row3[1] - tmp_top[10]*row3[2],
+        ];
+        let cofactor_row3 = [
+            tmp_top[2]*row2[2] + tmp_top[5]*row2[3] + tmp_top[1]*row2[1] - tmp_top[4]*row2[3] - tmp_top[0]*row2[1] - tmp_top[3]*row2[2],
+            tmp_top[8]*row2[3] + tmp_top[0]*row2[0] + tmp_top[7]*row2[2] - tmp_top[6]*row2[2] - tmp_top[9]*row2[3] - tmp_top[1]*row2[0],
+            tmp_top[6]*row2[1] + tmp_top[11]*row2[3] + tmp_top[3]*row2[0] - tmp_top[10]*row2[3] - tmp_top[2]*row2[0] - tmp_top[7]*row2[1],
+            tmp_top[10]*row2[2] + tmp_top[4]*row2[0] + tmp_top[9]*row2[1] - tmp_top[8]*row2[1] - tmp_top[11]*row2[2] - tmp_top[5]*row2[0],
+        ];
+
+        let det = (row0_simd * Simd::from_array(cofactor_row0)).reduce_sum();
+        if det.abs() < INVERSE_EPSILON {
+            return None;
+        }
+        let inv_det = Simd::<f64, 4>::splat(1.0/det);
+
+        // inverse[i][j] = adjugate[i][j]/det = cofactor_row_j[i]/det, so each
+        // inverse row is gathered across the cofactor rows at a fixed index.
+        let inv_row0 = [cofactor_row0[0], cofactor_row1[0], cofactor_row2[0], cofactor_row3[0]];
+        let inv_row1 = [cofactor_row0[1], cofactor_row1[1], cofactor_row2[1], cofactor_row3[1]];
+        let inv_row2 = [cofactor_row0[2], cofactor_row1[2], cofactor_row2[2], cofactor_row3[2]];
+        let inv_row3 = [cofactor_row0[3], cofactor_row1[3], cofactor_row2[3], cofactor_row3[3]];
+
+        let mut data = Vec::with_capacity(16);
+        data.extend_from_slice((Simd::from_array(inv_row0)*inv_det).as_array());
+        data.extend_from_slice((Simd::from_array(inv_row1)*inv_det).as_array());
+        data.extend_from_slice((Simd::from_array(inv_row2)*inv_det).as_array());
+        data.extend_from_slice((Simd::from_array(inv_row3)*inv_det).as_array());
+
+        Some(FloatMatrix { data, dim: (4, 4) })
+    }
 
-        let mut new_matrix_data = Vec::with_capacity(rhs.dim.0*self.dim.1);
+    /// Determinant via Gaussian elimination with partial pivoting: the
+    /// determinant of an upper-triangular matrix is the product of its
+    /// diagonal, and every row swap flips the sign.
+    fn gauss_jordan_det(&self) -> f64 {
+        let n = self.dim.0;
+        let mut work = self.data.clone();
+        let mut sign = 1.0;
+
+        for pivot in 0..n {
+            let pivot_row = (pivot..n)
+                .max_by(|&a, &b| work[a*n+pivot].abs().partial_cmp(&work[b*n+pivot].abs()).unwrap())
+                .unwrap();
+
+            if work[pivot_row*n+pivot].abs() < INVERSE_EPSILON {
+                return 0.0;
+            }
+
+            if pivot_row != pivot {
+                for col in 0..n {
+                    work.swap(pivot*n+col, pivot_row*n+col);
+                }
+                sign = -sign;
+            }
 
-        let mut simd_cols = Vec::with_capacity(rhs.dim.0);
-        for col in 0..rhs.dim.0 {
-            let mut col_vals = Vec::with_capacity(rhs.dim.1);
-            for col_val in 0..rhs.dim.1 {
-                col_vals.push(rhs.get_row(col_val)[col]);
+            for row in (pivot+1)..n {
+                let factor = work[row*n+pivot] / work[pivot*n+pivot];
+                for col in pivot..n {
+                    work[row*n+col] -= factor*work[pivot*n+col];
+                }
             }
-            simd_cols.push(col_vals);
         }
 
-        for row in 0..self.dim.1 {
-            let row_simd = self.get_row(row);
-            for col in &simd_cols {
-                let result = execute_mult_sum_simd(row_simd, col.as_slice());
-                new_matrix_data.push(result);
+        let mut det = sign;
+        for i in 0..n {
+            det *= work[i*n+i];
+        }
+        det
+    }
+
+    /// General inverse via Gauss-Jordan elimination with partial pivoting:
+    /// row-reduce `[self | I]` until the left half is the identity, at which
+    /// point the right half is the inverse.
+    fn inverse_gauss_jordan(&self) -> Option<FloatMatrix> {
+        let n = self.dim.0;
+        let mut work = self.data.clone();
+        let mut inv = vec![0.0; n*n];
+        for i in 0..n {
+            inv[i*n+i] = 1.0;
+        }
+
+        for pivot in 0..n {
+            let pivot_row = (pivot..n)
+                .max_by(|&a, &b| work[a*n+pivot].abs().partial_cmp(&work[b*n+pivot].abs()).unwrap())
+                .unwrap();
+
+            if work[pivot_row*n+pivot].abs() < INVERSE_EPSILON {
+                return None;
+            }
+
+            if pivot_row != pivot {
+                for col in 0..n {
+                    work.swap(pivot*n+col, pivot_row*n+col);
+                    inv.swap(pivot*n+col, pivot_row*n+col);
+                }
+            }
+
+            let pivot_val = work[pivot*n+pivot];
+            for col in 0..n {
+                work[pivot*n+col] /= pivot_val;
+                inv[pivot*n+col] /= pivot_val;
+            }
+
+            for row in 0..n {
+                if row == pivot {
+                    continue;
+                }
+                let factor = work[row*n+pivot];
+                for col in 0..n {
+                    work[row*n+col] -= factor*work[pivot*n+col];
+                    inv[row*n+col] -= factor*inv[pivot*n+col];
+                }
             }
         }
 
-        Self { data: new_matrix_data, dim: (rhs.dim.0, self.dim.1) }
+        Some(FloatMatrix { data: inv, dim: (n, n) })
     }
 }
 
@@ -159,7 +567,7 @@ mod tests {
 
     #[test]
     fn different_sized_small_matrix() {
-        let m1 = FloatMatrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 4, 2);
+        let m1 = FloatMatrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], 4, 2);
         let m2 = FloatMatrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0], 3, 4);
 
         let m3 = m1 * m2;
@@ -179,12 +587,123 @@ mod tests {
 
         let m3 = m1 * m2;
 
-        assert_eq!(m3.as_raw(), vec![2850.0, 2895.0, 2940.0, 2985.0, 3030.0, 3075.0, 3120.0, 3165.0, 3210.0, 3255.0, 7350.0, 7495.0, 7640.0, 7785.0, 7930.0, 
-                                    8075.0, 8220.0, 8365.0, 8510.0, 8655.0, 11850.0, 12095.0, 12340.0, 12585.0, 12830.0, 13075.0, 13320.0, 13565.0, 13810.0, 14055.0, 
-                                    16350.0, 16695.0, 17040.0, 17385.0, 17730.0, 18075.0, 18420.0, 18765.0, 19110.0, 19455.0, 20850.0, 21295.0, 21740.0, 22185.0, 
-                                    22630.0, 23075.0, 23520.0, 23965.0, 24410.0, 24855.0, 25350.0, 25895.0, 26440.0, 26985.0, 27530.0, 28075.0, 28620.0, 29165.0, 
-                                    29710.0, 30255.0, 29850.0, 30495.0, 31140.0, 31785.0, 32430.0, 33075.0, 33720.0, 34365.0, 35010.0, 35655.0, 34350.0, 35095.0, 
-                                    35840.0, 36585.0, 37330.0, 38075.0, 38820.0, 39565.0, 40310.0, 41055.0, 38850.0, 39695.0, 40540.0, 41385.0, 42230.0, 43075.0, 
+        assert_eq!(m3.as_raw(), vec![2850.0, 2895.0, 2940.0, 2985.0, 3030.0, 3075.0, 3120.0, 3165.0, 3210.0, 3255.0, 7350.0, 7495.0, 7640.0, 7785.0, 7930.0,
+                                    8075.0, 8220.0, 8365.0, 8510.0, 8655.0, 11850.0, 12095.0, 12340.0, 12585.0, 12830.0, 13075.0, 13320.0, 13565.0, 13810.0, 14055.0,
+                                    16350.0, 16695.0, 17040.0, 17385.0, 17730.0, 18075.0, 18420.0, 18765.0, 19110.0, 19455.0, 20850.0, 21295.0, 21740.0, 22185.0,
+                                    22630.0, 23075.0, 23520.0, 23965.0, 24410.0, 24855.0, 25350.0, 25895.0, 26440.0, 26985.0, 27530.0, 28075.0, 28620.0, 29165.0,
+                                    29710.0, 30255.0, 29850.0, 30495.0, 31140.0, 31785.0, 32430.0, 33075.0, 33720.0, 34365.0, 35010.0, 35655.0, 34350.0, 35095.0,
+                                    35840.0, 36585.0, 37330.0, 38075.0, 38820.0, 39565.0, 40310.0, 41055.0, 38850.0, 39695.0, 40540.0, 41385.0, 42230.0, 43075.0,
+                                    43920.0, 44765.0, 45610.0, 46455.0, 43350.0, 44295.0, 45240.0, 46185.0, 47130.0, 48075.0, 49020.0, 49965.0, 50910.0, 51855.0])
+    }
+
+    #[test]
+    fn f32_square_matrix() {
+        let m1 = F32Matrix::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0], 3, 3);
+        let m2 = m1.clone();
+
+        let m3 = m1 * m2;
+
+        assert_eq!(m3.as_raw(), vec![30.0, 36.0, 42.0, 66.0, 81.0, 96.0, 102.0, 126.0, 150.0])
+    }
+
+    #[test]
+    fn blocked_mult_matches_medium_square_matrix() {
+        let mut m1_data = vec![];
+        for i in 0..100 {
+            m1_data.push(i as f64);
+        }
+
+        let m1 = FloatMatrix::new(m1_data, 10, 10);
+        let m2 = m1.clone();
+
+        let m3 = m1.blocked_mult(m2);
+
+        assert_eq!(m3.as_raw(), vec![2850.0, 2895.0, 2940.0, 2985.0, 3030.0, 3075.0, 3120.0, 3165.0, 3210.0, 3255.0, 7350.0, 7495.0, 7640.0, 7785.0, 7930.0,
+                                    8075.0, 8220.0, 8365.0, 8510.0, 8655.0, 11850.0, 12095.0, 12340.0, 12585.0, 12830.0, 13075.0, 13320.0, 13565.0, 13810.0, 14055.0,
+                                    16350.0, 16695.0, 17040.0, 17385.0, 17730.0, 18075.0, 18420.0, 18765.0, 19110.0, 19455.0, 20850.0, 21295.0, 21740.0, 22185.0,
+                                    22630.0, 23075.0, 23520.0, 23965.0, 24410.0, 24855.0, 25350.0, 25895.0, 26440.0, 26985.0, 27530.0, 28075.0, 28620.0, 29165.0,
+                                    29710.0, 30255.0, 29850.0, 30495.0, 31140.0, 31785.0, 32430.0, 33075.0, 33720.0, 34365.0, 35010.0, 35655.0, 34350.0, 35095.0,
+                                    35840.0, 36585.0, 37330.0, 38075.0, 38820.0, 39565.0, 40310.0, 41055.0, 38850.0, 39695.0, 40540.0, 41385.0, 42230.0, 43075.0,
                                     43920.0, 44765.0, 45610.0, 46455.0, 43350.0, 44295.0, 45240.0, 46185.0, 47130.0, 48075.0, 49020.0, 49965.0, 50910.0, 51855.0])
     }
+
+    #[test]
+    fn det_4x4() {
+        let m = FloatMatrix::new(vec![
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0,
+        ], 4, 4);
+
+        assert_eq!(m.det(), 0.0);
+    }
+
+    #[test]
+    fn inverse_4x4_diagonal() {
+        let m = FloatMatrix::new(vec![
+            4.0, 0.0, 0.0, 0.0,
+            0.0, 3.0, 0.0, 0.0,
+            0.0, 0.0, 2.0, 0.0,
+            0.0, 0.0, 0.0, 5.0,
+        ], 4, 4);
+
+        let inv = m.inverse().unwrap();
+
+        assert_eq!(inv.as_raw(), vec![
+            0.25, 0.0, 0.0, 0.0,
+            0.0, 1.0/3.0, 0.0, 0.0,
+            0.0, 0.0, 0.5, 0.0,
+            0.0, 0.0, 0.0, 0.2,
+        ]);
+    }
+
+    #[test]
+    fn inverse_4x4_non_symmetric_roundtrip() {
+        let m = FloatMatrix::new(vec![
+            1.0, 2.0, 3.0, 4.0,
+            0.0, 1.0, 2.0, 3.0,
+            0.0, 0.0, 1.0, 2.0,
+            5.0, 0.0, 0.0, 1.0,
+        ], 4, 4);
+
+        let inv = m.inverse().unwrap();
+        let identity = m * inv;
+
+        for row in 0..4 {
+            for col in 0..4 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert!((identity.get_row(row)[col] - expected).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_4x4_singular_is_none() {
+        let m = FloatMatrix::new(vec![
+            1.0, 2.0, 3.0, 4.0,
+            5.0, 6.0, 7.0, 8.0,
+            9.0, 10.0, 11.0, 12.0,
+            13.0, 14.0, 15.0, 16.0,
+        ], 4, 4);
+
+        assert_eq!(m.inverse(), None);
+    }
+
+    #[test]
+    fn inverse_3x3_gauss_jordan() {
+        let m = FloatMatrix::new(vec![
+            2.0, 0.0, 0.0,
+            0.0, 4.0, 0.0,
+            0.0, 0.0, 8.0,
+        ], 3, 3);
+
+        let inv = m.inverse().unwrap();
+
+        assert_eq!(inv.as_raw(), vec![
+            0.5, 0.0, 0.0,
+            0.0, 0.25, 0.0,
+            0.0, 0.0, 0.125,
+        ]);
+    }
 }
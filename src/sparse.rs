@@ -0,0 +1,127 @@
+use std::ops::Mul;
+
+use crate::matrix::{execute_mult_sum_simd, FloatMatrix};
+
+/// A matrix stored in compressed sparse row (CSR) form: only the nonzero
+/// entries are kept, as `values` alongside the column each one lives in
+/// (`col_indices`), with `row_ptr[r]..row_ptr[r+1]` indexing the slice of
+/// both that belongs to row `r`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMatrix {
+    values: Vec<f64>,
+    col_indices: Vec<usize>,
+    row_ptr: Vec<usize>,
+    dim: (usize, usize)
+}
+
+impl SparseMatrix {
+    pub fn new(values: Vec<f64>, col_indices: Vec<usize>, row_ptr: Vec<usize>, width: usize, height: usize) -> Self {
+        assert_eq!(values.len(), col_indices.len());
+        assert_eq!(row_ptr.len(), height+1);
+        Self { values, col_indices, row_ptr, dim: (width, height) }
+    }
+
+    pub fn dim(&self) -> (usize, usize) {
+        self.dim
+    }
+
+    /// Materialize back into a dense [`FloatMatrix`], filling every entry not
+    /// present in `values` with zero.
+    pub fn to_dense(&self) -> FloatMatrix {
+        let mut data = vec![0.0; self.dim.0*self.dim.1];
+
+        for row in 0..self.dim.1 {
+            for idx in self.row_ptr[row]..self.row_ptr[row+1] {
+                let col = self.col_indices[idx];
+                data[row*self.dim.0 + col] = self.values[idx];
+            }
+        }
+
+        FloatMatrix::new(data, self.dim.0, self.dim.1)
+    }
+}
+
+impl FloatMatrix {
+    /// Build a [`SparseMatrix`] from this dense matrix, dropping any entry
+    /// whose magnitude is at or below `threshold`.
+    pub fn to_sparse(&self, threshold: f64) -> SparseMatrix {
+        let (width, height) = self.dim();
+
+        let mut values = Vec::new();
+        let mut col_indices = Vec::new();
+        let mut row_ptr = Vec::with_capacity(height+1);
+        row_ptr.push(0);
+
+        for row in 0..height {
+            for (col, &val) in self.get_row(row).iter().enumerate() {
+                if val.abs() > threshold {
+                    values.push(val);
+                    col_indices.push(col);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+
+        SparseMatrix { values, col_indices, row_ptr, dim: (width, height) }
+    }
+}
+
+impl Mul<FloatMatrix> for SparseMatrix {
+    type Output = FloatMatrix;
+
+    /// Sparse-times-dense multiply: each output row only does as much work as
+    /// its sparse row has nonzeros for, gathering the matching dense entries
+    /// out of `rhs` one output column at a time and running the same SIMD dot
+    /// product the dense path uses.
+    fn mul(self, rhs: FloatMatrix) -> FloatMatrix {
+        let (n, k_rhs) = rhs.dim();
+        let (k_self, m) = self.dim;
+        assert_eq!(k_self, k_rhs);
+
+        let mut data = vec![0.0; n*m];
+        let mut gathered = Vec::new();
+
+        for row in 0..m {
+            let start = self.row_ptr[row];
+            let end = self.row_ptr[row+1];
+            if start == end {
+                continue;
+            }
+
+            let row_values = &self.values[start..end];
+            let row_cols = &self.col_indices[start..end];
+
+            for col in 0..n {
+                gathered.clear();
+                gathered.extend(row_cols.iter().map(|&k| rhs.get_row(k)[col]));
+                data[row*n + col] = execute_mult_sum_simd(row_values, &gathered);
+            }
+        }
+
+        FloatMatrix::new(data, n, m)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_sparse_drops_near_zero_entries() {
+        let dense = FloatMatrix::new(vec![1.0, 0.0, 0.0, 2.0], 2, 2);
+        let sparse = dense.to_sparse(1e-9);
+
+        assert_eq!(sparse.to_dense().as_raw(), vec![1.0, 0.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn sparse_dense_mult_matches_dense() {
+        let dense = FloatMatrix::new(vec![1.0, 0.0, 0.0, 2.0], 2, 2);
+        let sparse = dense.to_sparse(1e-9);
+        let rhs = FloatMatrix::new(vec![5.0, 6.0, 7.0, 8.0], 2, 2);
+
+        let actual = sparse * rhs;
+
+        assert_eq!(actual.as_raw(), vec![5.0, 6.0, 14.0, 16.0]);
+    }
+}